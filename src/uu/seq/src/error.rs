@@ -0,0 +1,50 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+use std::fmt::{Display, Formatter, Result};
+
+use uucore::error::UError;
+
+use crate::numberparse::ParseNumberError;
+
+/// An error returned while parsing or validating the `seq` arguments.
+#[derive(Debug)]
+pub enum SeqError {
+    /// Failed to parse one of the `first`/`increment`/`last` arguments.
+    ParseError(String, ParseNumberError),
+    /// The increment argument was zero, which would never reach `last`.
+    ZeroIncrement(String),
+    /// No arguments were given at all.
+    NoArguments,
+    /// A `%x`/`%X`/`%o`/`%b` format was given a non-integral argument.
+    NonIntegerArgument(String, char),
+}
+
+impl Display for SeqError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            Self::ParseError(s, e) => write!(f, "invalid argument '{s}': {e:?}"),
+            Self::ZeroIncrement(s) => write!(f, "invalid Zero increment value: '{s}'"),
+            Self::NoArguments => write!(f, "missing operand"),
+            Self::NonIntegerArgument(label, conversion) => write!(
+                f,
+                "invalid {label} argument: '%{conversion}' requires an integer value"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SeqError {}
+
+impl UError for SeqError {
+    fn code(&self) -> i32 {
+        1
+    }
+}
+
+impl From<SeqError> for Box<dyn UError> {
+    fn from(e: SeqError) -> Self {
+        Box::new(e)
+    }
+}
@@ -6,7 +6,9 @@
 use std::ffi::OsString;
 use std::io::{stdout, ErrorKind, Write};
 
+use bigdecimal::{BigDecimal, RoundingMode};
 use clap::{Arg, ArgAction, Command};
+use num_bigint::{BigInt, Sign};
 use num_traits::{ToPrimitive, Zero};
 
 use uucore::error::{FromIo, UResult};
@@ -34,6 +36,7 @@ const OPT_SEPARATOR: &str = "separator";
 const OPT_TERMINATOR: &str = "terminator";
 const OPT_EQUAL_WIDTH: &str = "equal-width";
 const OPT_FORMAT: &str = "format";
+const OPT_ROUND: &str = "round";
 
 const ARG_NUMBERS: &str = "numbers";
 
@@ -43,6 +46,24 @@ struct SeqOptions<'a> {
     terminator: String,
     equal_width: bool,
     format: Option<&'a str>,
+    round: Option<RoundingMode>,
+}
+
+/// Parse the `--round=MODE` argument into a `bigdecimal` rounding mode.
+fn parse_rounding_mode(mode: &str) -> Result<RoundingMode, String> {
+    match mode {
+        "down" | "toward-zero" => Ok(RoundingMode::Down),
+        "up" => Ok(RoundingMode::Up),
+        "floor" => Ok(RoundingMode::Floor),
+        "ceiling" => Ok(RoundingMode::Ceiling),
+        "half-up" => Ok(RoundingMode::HalfUp),
+        "half-even" => Ok(RoundingMode::HalfEven),
+        "half-down" => Ok(RoundingMode::HalfDown),
+        _ => Err(format!(
+            "invalid rounding mode '{mode}' \
+             (expected one of: down, up, floor, ceiling, half-up, half-even, half-down, toward-zero)"
+        )),
+    }
 }
 
 /// A range of floats.
@@ -110,6 +131,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
             .to_string(),
         equal_width: matches.get_flag(OPT_EQUAL_WIDTH),
         format: matches.get_one::<String>(OPT_FORMAT).map(|s| s.as_str()),
+        round: matches.get_one::<RoundingMode>(OPT_ROUND).copied(),
     };
 
     let (first, first_precision) = if numbers.len() > 1 {
@@ -149,19 +171,37 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
 
     let precision = select_precision(first_precision, increment_precision, last_precision);
 
-    let format = options
-        .format
-        .map(Format::<num_format::Float>::parse)
-        .transpose()?;
+    let format = options.format.map(SeqFormat::parse).transpose()?;
+
+    if let Some(SeqFormat::Numeric(conversion)) = &format {
+        if matches!(conversion.conversion, 'x' | 'X' | 'o' | 'b') {
+            for (label, value) in [
+                ("first", &first.number),
+                ("increment", &increment.number),
+                ("last", &last.number),
+            ] {
+                if !is_integer_value(value) {
+                    return Err(SeqError::NonIntegerArgument(
+                        label.to_owned(),
+                        conversion.conversion,
+                    )
+                    .into());
+                }
+            }
+        }
+    }
 
     let result = print_seq(
         (first.number, increment.number, last.number),
-        precision,
-        &options.separator,
-        &options.terminator,
         options.equal_width,
-        padding,
-        format.as_ref(),
+        RenderOptions {
+            precision,
+            separator: &options.separator,
+            terminator: &options.terminator,
+            padding,
+            format: format.as_ref(),
+            round: options.round,
+        },
     );
     match result {
         Ok(()) => Ok(()),
@@ -202,6 +242,16 @@ pub fn uu_app() -> Command {
                 .long(OPT_FORMAT)
                 .help("use printf style floating-point FORMAT"),
         )
+        .arg(
+            Arg::new(OPT_ROUND)
+                .long(OPT_ROUND)
+                .value_name("MODE")
+                .value_parser(parse_rounding_mode)
+                .help(
+                    "round output to the selected precision using MODE: down, up, floor, \
+                     ceiling, half-up, half-even, half-down, toward-zero",
+                ),
+        )
         .arg(
             // we use allow_hyphen_values instead of allow_negative_numbers because clap removed
             // the support for "exotic" negative numbers like -.1 (see https://github.com/clap-rs/clap/discussions/5837)
@@ -226,12 +276,481 @@ fn format_bigdecimal(value: &bigdecimal::BigDecimal) -> Option<String> {
     String::from_utf8(value_as_bytes).ok()
 }
 
+/// A `-f FORMAT` template that is *exactly* a single printf-style numeric
+/// conversion, e.g. `"%.40f"` or `"%+08.2e"`.
+///
+/// `seq` can render these directly from the arbitrary-precision
+/// `ExtendedBigDecimal` without ever converting through `f64`, which is
+/// what lets `seq -f '%.40f' 0 0.1 1` round-trip exactly. Anything else
+/// (literal text around the conversion, more than one conversion, an
+/// unsupported conversion character) falls back to the general-purpose
+/// `Format<num_format::Float>` engine.
+#[derive(Clone, Copy, Debug)]
+struct NumericConversion {
+    flag_minus: bool,
+    flag_zero: bool,
+    flag_alt: bool,
+    flag_plus: bool,
+    flag_space: bool,
+    precision: Option<usize>,
+    width: Option<usize>,
+    conversion: char,
+}
+
+/// Parse `spec` as a bare numeric conversion understood by
+/// [`NumericConversion`]. Returns `None` if `spec` is anything more than
+/// `%[flags][width][.precision]conversion`.
+fn parse_numeric_format(spec: &str) -> Option<NumericConversion> {
+    let mut chars = spec.chars().peekable();
+    if chars.next()? != '%' {
+        return None;
+    }
+
+    let mut flag_minus = false;
+    let mut flag_zero = false;
+    let mut flag_alt = false;
+    let mut flag_plus = false;
+    let mut flag_space = false;
+    while let Some(&c) = chars.peek() {
+        match c {
+            '-' => flag_minus = true,
+            '0' => flag_zero = true,
+            '#' => flag_alt = true,
+            '+' => flag_plus = true,
+            ' ' => flag_space = true,
+            _ => break,
+        }
+        chars.next();
+    }
+
+    let mut width_str = String::new();
+    while let Some(&c) = chars.peek() {
+        if c.is_ascii_digit() {
+            width_str.push(c);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    let width = if width_str.is_empty() {
+        None
+    } else {
+        Some(width_str.parse().ok()?)
+    };
+
+    let mut precision = None;
+    if chars.peek() == Some(&'.') {
+        chars.next();
+        let mut precision_str = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() {
+                precision_str.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        precision = Some(precision_str.parse().unwrap_or(0));
+    }
+
+    let conversion = chars.next()?;
+    if chars.next().is_some() {
+        // Trailing characters mean this isn't a bare single conversion.
+        return None;
+    }
+    if !matches!(
+        conversion,
+        'f' | 'e' | 'E' | 'g' | 'G' | 'x' | 'X' | 'o' | 'b'
+    ) {
+        return None;
+    }
+
+    Some(NumericConversion {
+        flag_minus,
+        flag_zero,
+        flag_alt,
+        flag_plus,
+        flag_space,
+        precision,
+        width,
+        conversion,
+    })
+}
+
+/// Render a `BigDecimal` as fixed-point text with exactly `precision`
+/// fractional digits, rounded per `mode`. `alt` is the `#` flag: it forces
+/// a trailing `.` when `precision` is `0`.
+fn format_bigdecimal_fixed(
+    value: &BigDecimal,
+    precision: usize,
+    mode: RoundingMode,
+    alt: bool,
+) -> String {
+    let rounded = value.with_scale_round(precision as i64, mode);
+    let (unscaled, _) = rounded.as_bigint_and_exponent();
+    let negative = unscaled.sign() == Sign::Minus;
+    let mut digits = unscaled.magnitude().to_str_radix(10);
+    if digits.len() <= precision {
+        digits = format!("{}{digits}", "0".repeat(precision - digits.len() + 1));
+    }
+    let split_at = digits.len() - precision;
+    let (int_part, frac_part) = digits.split_at(split_at);
+
+    let mut out = String::new();
+    if negative {
+        out.push('-');
+    }
+    out.push_str(int_part);
+    if precision > 0 {
+        out.push('.');
+        out.push_str(frac_part);
+    } else if alt {
+        out.push('.');
+    }
+    out
+}
+
+/// Render a `BigDecimal` as scientific notation `d.ddde±NN`, with exactly
+/// `precision` digits after the decimal point, rounded per `mode`. `alt` is
+/// the `#` flag: it forces a trailing `.` when `precision` is `0`.
+fn format_bigdecimal_exp(
+    value: &BigDecimal,
+    precision: usize,
+    upper: bool,
+    mode: RoundingMode,
+    alt: bool,
+) -> String {
+    let e = if upper { 'E' } else { 'e' };
+    let (unscaled, scale) = value.as_bigint_and_exponent();
+    if unscaled.sign() == Sign::NoSign {
+        let mantissa = if precision > 0 {
+            format!("0.{}", "0".repeat(precision))
+        } else if alt {
+            "0.".to_owned()
+        } else {
+            "0".to_owned()
+        };
+        return format!("{mantissa}{e}+00");
+    }
+
+    let negative = unscaled.sign() == Sign::Minus;
+    let digit_count = unscaled.magnitude().to_str_radix(10).len() as i64;
+    let mut exp = (digit_count - 1) - scale;
+
+    // Round to `precision + 1` significant digits (one leading digit plus
+    // `precision` after the decimal point) by rounding to the equivalent
+    // number of fractional places, which lets `with_scale_round` handle
+    // both the chosen rounding mode and any resulting carry.
+    let keep = precision as i64 + 1;
+    let rounded = value.with_scale_round(scale + (keep - digit_count), mode);
+    let (rounded_unscaled, _) = rounded.as_bigint_and_exponent();
+    let mut mantissa_digits = rounded_unscaled.magnitude().to_str_radix(10);
+    // A carry can grow the kept digits by one (e.g. "99" -> "100");
+    // drop the extra trailing digit and bump the exponent instead.
+    if mantissa_digits.len() as i64 > keep {
+        mantissa_digits.pop();
+        exp += 1;
+    }
+
+    let (first, rest) = mantissa_digits.split_at(1);
+    let mut mantissa = first.to_owned();
+    if precision > 0 {
+        mantissa.push('.');
+        mantissa.push_str(rest);
+    } else if alt {
+        mantissa.push('.');
+    }
+
+    let exp_sign = if exp < 0 { '-' } else { '+' };
+    format!(
+        "{}{mantissa}{e}{exp_sign}{:02}",
+        if negative { "-" } else { "" },
+        exp.abs()
+    )
+}
+
+/// Strip trailing fractional zeros (and a trailing `.`) from plain
+/// fixed-point text, as `%g` does when no `#` flag is given.
+fn strip_trailing_zeros_fixed(s: &str) -> String {
+    if !s.contains('.') {
+        return s.to_owned();
+    }
+    s.trim_end_matches('0').trim_end_matches('.').to_owned()
+}
+
+/// Same, but for scientific notation: strip zeros from the mantissa
+/// before the `e`/`E` exponent marker.
+fn strip_trailing_zeros_exp(s: &str) -> String {
+    let Some(e_pos) = s.find(['e', 'E']) else {
+        return s.to_owned();
+    };
+    let (mantissa, exp_part) = s.split_at(e_pos);
+    format!("{}{exp_part}", strip_trailing_zeros_fixed(mantissa))
+}
+
+/// Render a `BigDecimal` for `%g`/`%G`: scientific notation when the
+/// value's decimal exponent (taken before rounding) is below `-4` or at
+/// least the number of significant digits, fixed-point otherwise.
+/// Trailing zeros are stripped unless `alt` (the `#` flag) is set.
+fn format_bigdecimal_general(
+    value: &BigDecimal,
+    precision: Option<usize>,
+    upper: bool,
+    mode: RoundingMode,
+    alt: bool,
+) -> String {
+    let significant = match precision {
+        Some(0) => 1,
+        Some(p) => p,
+        None => 6,
+    };
+    let (unscaled, scale) = value.as_bigint_and_exponent();
+    if unscaled.sign() == Sign::NoSign {
+        let rendered = format_bigdecimal_fixed(value, significant - 1, mode, alt);
+        return if alt {
+            rendered
+        } else {
+            strip_trailing_zeros_fixed(&rendered)
+        };
+    }
+    let digits = unscaled.magnitude().to_str_radix(10);
+    let exp = (digits.len() as i64 - 1) - scale;
+
+    if exp < -4 || exp >= significant as i64 {
+        let rendered = format_bigdecimal_exp(value, significant - 1, upper, mode, alt);
+        if alt {
+            rendered
+        } else {
+            strip_trailing_zeros_exp(&rendered)
+        }
+    } else {
+        let frac_digits = (significant as i64 - 1 - exp).max(0) as usize;
+        let rendered = format_bigdecimal_fixed(value, frac_digits, mode, alt);
+        if alt {
+            rendered
+        } else {
+            strip_trailing_zeros_fixed(&rendered)
+        }
+    }
+}
+
+/// Apply the width/justification flags of `conversion` to an already
+/// rendered numeric `body`.
+fn write_conversion_width(
+    writer: &mut impl Write,
+    conversion: &NumericConversion,
+    body: &str,
+) -> std::io::Result<()> {
+    let width = conversion.width.unwrap_or(0);
+    if body.len() >= width {
+        return write!(writer, "{body}");
+    }
+    let pad = width - body.len();
+    if conversion.flag_minus {
+        write!(writer, "{body}{}", " ".repeat(pad))
+    } else if conversion.flag_zero {
+        let (prefix, digits) = split_sign_and_radix_prefix(body);
+        write!(writer, "{prefix}{}{digits}", "0".repeat(pad))
+    } else {
+        write!(writer, "{}{body}", " ".repeat(pad))
+    }
+}
+
+/// Split `body` into a leading `-`/`+`/`0x`/`0X`/`0b` prefix and the digits
+/// that follow, so zero-padding can be inserted after the prefix instead
+/// of splitting it apart (e.g. `%#010x` of `1` is `0x00000001`, not
+/// `000000000x1`).
+fn split_sign_and_radix_prefix(body: &str) -> (&str, &str) {
+    let after_sign = body
+        .strip_prefix('-')
+        .or_else(|| body.strip_prefix('+'))
+        .unwrap_or(body);
+    let sign_len = body.len() - after_sign.len();
+    let radix_len = if after_sign.starts_with("0x")
+        || after_sign.starts_with("0X")
+        || after_sign.starts_with("0b")
+    {
+        2
+    } else {
+        0
+    };
+    body.split_at(sign_len + radix_len)
+}
+
+/// Zero-pad `body` to `width`, inserting the padding after its sign/radix
+/// prefix rather than space-padding it (as `{:>0width$}` would do for a
+/// plain `String`, which has no numeric fill).
+fn pad_numeric_zero(body: &str, width: usize) -> String {
+    if body.len() >= width {
+        return body.to_owned();
+    }
+    let pad = width - body.len();
+    let (prefix, digits) = split_sign_and_radix_prefix(body);
+    format!("{prefix}{}{digits}", "0".repeat(pad))
+}
+
+/// Whether `value` can be converted to an exact `BigInt` without losing
+/// information, as required by the `%x`/`%X`/`%o`/`%b` conversions.
+fn is_integer_value(value: &ExtendedBigDecimal) -> bool {
+    match value {
+        ExtendedBigDecimal::BigDecimal(bd) => bd.is_integer(),
+        ExtendedBigDecimal::MinusZero => true,
+        ExtendedBigDecimal::Infinity
+        | ExtendedBigDecimal::MinusInfinity
+        | ExtendedBigDecimal::Nan => false,
+    }
+}
+
+/// Render a non-negative-magnitude `BigInt` as `%x`/`%X`/`%o`/`%b` text,
+/// honoring the `#` alternate-form prefix.
+fn format_bigint_radix(value: &BigInt, conversion: char, alt: bool) -> String {
+    let negative = value.sign() == Sign::Minus;
+    let radix = match conversion {
+        'x' | 'X' => 16,
+        'o' => 8,
+        'b' => 2,
+        _ => unreachable!("unsupported radix conversion"),
+    };
+    let mut digits = value.magnitude().to_str_radix(radix);
+    if conversion == 'X' {
+        digits = digits.to_uppercase();
+    }
+    let prefix = match (alt, conversion) {
+        (true, 'x') => "0x",
+        (true, 'X') => "0X",
+        (true, 'b') => "0b",
+        (true, 'o') if !digits.starts_with('0') => "0",
+        _ => "",
+    };
+    format!("{}{prefix}{digits}", if negative { "-" } else { "" })
+}
+
+/// Apply the `+`/space sign flags to an already-rendered, non-negative
+/// `body`, as printf does for the signed `f`/`e`/`E`/`g`/`G` conversions
+/// (the unsigned `x`/`X`/`o`/`b` conversions ignore both flags).
+fn apply_sign_flags(conversion: &NumericConversion, body: String) -> String {
+    if body.starts_with('-') || matches!(conversion.conversion, 'x' | 'X' | 'o' | 'b') {
+        return body;
+    }
+    if conversion.flag_plus {
+        format!("+{body}")
+    } else if conversion.flag_space {
+        format!(" {body}")
+    } else {
+        body
+    }
+}
+
+/// Render `value` for a single numeric `-f` conversion, bypassing the
+/// lossy `f64` round-trip for finite values.
+fn write_numeric(
+    writer: &mut impl Write,
+    conversion: &NumericConversion,
+    value: &ExtendedBigDecimal,
+    round: Option<RoundingMode>,
+) -> std::io::Result<()> {
+    let precision = conversion.precision.unwrap_or(6);
+    let mode = round.unwrap_or(RoundingMode::HalfEven);
+    let body = match value {
+        ExtendedBigDecimal::BigDecimal(bd) => match conversion.conversion {
+            'f' => format_bigdecimal_fixed(bd, precision, mode, conversion.flag_alt),
+            'e' => format_bigdecimal_exp(bd, precision, false, mode, conversion.flag_alt),
+            'E' => format_bigdecimal_exp(bd, precision, true, mode, conversion.flag_alt),
+            'g' => format_bigdecimal_general(
+                bd,
+                conversion.precision,
+                false,
+                mode,
+                conversion.flag_alt,
+            ),
+            'G' => {
+                format_bigdecimal_general(bd, conversion.precision, true, mode, conversion.flag_alt)
+            }
+            conv @ ('x' | 'X' | 'o' | 'b') => {
+                let integer = bd.with_scale(0).as_bigint_and_exponent().0;
+                format_bigint_radix(&integer, conv, conversion.flag_alt)
+            }
+            _ => unreachable!("unsupported conversion reached write_numeric"),
+        },
+        // `Inf`/`-Inf` have no arbitrary-precision representation; fall
+        // back to the `f64` formatting that `sprintf` already knows.
+        ExtendedBigDecimal::Infinity | ExtendedBigDecimal::MinusInfinity => {
+            let float = if matches!(value, ExtendedBigDecimal::Infinity) {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            };
+            let spec = format!(
+                "%.{precision}{}",
+                conversion.conversion.to_ascii_lowercase()
+            );
+            let format_arguments = &[FormatArgument::Float(float)];
+            String::from_utf8(sprintf(&spec, format_arguments).unwrap_or_default())
+                .unwrap_or_default()
+        }
+        ExtendedBigDecimal::MinusZero => match conversion.conversion {
+            'f' => format!(
+                "-{}",
+                format_bigdecimal_fixed(&BigDecimal::from(0), precision, mode, conversion.flag_alt)
+            ),
+            'e' => format!(
+                "-{}",
+                format_bigdecimal_exp(
+                    &BigDecimal::from(0),
+                    precision,
+                    false,
+                    mode,
+                    conversion.flag_alt
+                )
+            ),
+            'E' => format!(
+                "-{}",
+                format_bigdecimal_exp(
+                    &BigDecimal::from(0),
+                    precision,
+                    true,
+                    mode,
+                    conversion.flag_alt
+                )
+            ),
+            'g' | 'G' => "-0".to_owned(),
+            'x' | 'X' | 'o' | 'b' => {
+                format_bigint_radix(&BigInt::zero(), conversion.conversion, conversion.flag_alt)
+            }
+            _ => unreachable!("unsupported conversion reached write_numeric"),
+        },
+        ExtendedBigDecimal::Nan => "nan".to_owned(),
+    };
+    let body = apply_sign_flags(conversion, body);
+    write_conversion_width(writer, conversion, &body)
+}
+
+/// A `-f FORMAT` template, parsed once before the sequence is generated.
+enum SeqFormat {
+    /// A bare numeric conversion, rendered directly from the
+    /// arbitrary-precision `ExtendedBigDecimal` (see [`NumericConversion`]).
+    Numeric(NumericConversion),
+    /// Anything else still goes through the general-purpose `printf`-style
+    /// formatter, which only understands `f64`.
+    General(Format<num_format::Float>),
+}
+
+impl SeqFormat {
+    fn parse(spec: &str) -> UResult<Self> {
+        match parse_numeric_format(spec) {
+            Some(conversion) => Ok(Self::Numeric(conversion)),
+            None => Ok(Self::General(Format::<num_format::Float>::parse(spec)?)),
+        }
+    }
+}
+
 /// Write a big decimal formatted according to the given parameters.
 fn write_value_float(
     writer: &mut impl Write,
     value: &ExtendedBigDecimal,
     width: usize,
     precision: Option<usize>,
+    round: Option<RoundingMode>,
 ) -> std::io::Result<()> {
     let value_as_str = match precision {
         // format with precision: decimal floats and integers
@@ -239,6 +758,10 @@ fn write_value_float(
             ExtendedBigDecimal::Infinity | ExtendedBigDecimal::MinusInfinity => {
                 format!("{value:>width$.precision$}")
             }
+            ExtendedBigDecimal::BigDecimal(bd) => {
+                let mode = round.unwrap_or(RoundingMode::HalfEven);
+                pad_numeric_zero(&format_bigdecimal_fixed(bd, precision, mode, false), width)
+            }
             _ => format!("{value:>0width$.precision$}"),
         },
         // format without precision: hexadecimal floats
@@ -252,68 +775,220 @@ fn write_value_float(
     write!(writer, "{value_as_str}")
 }
 
-/// Floating point based code path
-fn print_seq(
-    range: RangeFloat,
+/// The per-render knobs shared by the chunked and incremental code paths:
+/// everything needed to print one element or the separator/terminator
+/// around it.
+struct RenderOptions<'a> {
     precision: Option<usize>,
-    separator: &str,
-    terminator: &str,
-    pad: bool,
+    separator: &'a str,
+    terminator: &'a str,
     padding: usize,
-    format: Option<&Format<num_format::Float>>,
+    format: Option<&'a SeqFormat>,
+    round: Option<RoundingMode>,
+}
+
+/// Render one sequence element using the active `-f`/`--round` settings.
+fn render_term(
+    writer: &mut impl Write,
+    value: &ExtendedBigDecimal,
+    options: &RenderOptions,
+) -> std::io::Result<()> {
+    match options.format {
+        Some(SeqFormat::Numeric(conversion)) => {
+            write_numeric(writer, conversion, value, options.round)
+        }
+        Some(SeqFormat::General(f)) => {
+            let float = match value {
+                ExtendedBigDecimal::BigDecimal(bd) => bd.to_f64().unwrap(),
+                ExtendedBigDecimal::Infinity => f64::INFINITY,
+                ExtendedBigDecimal::MinusInfinity => f64::NEG_INFINITY,
+                ExtendedBigDecimal::MinusZero => -0.0,
+                ExtendedBigDecimal::Nan => f64::NAN,
+            };
+            f.fmt(writer, float)
+        }
+        None => write_value_float(
+            writer,
+            value,
+            options.padding,
+            options.precision,
+            options.round,
+        ),
+    }
+}
+
+/// Divide the `BigInt`s `num` by `den`, rounding towards negative
+/// infinity (unlike the truncating `/` operator).
+fn div_floor_bigint(num: &BigInt, den: &BigInt) -> BigInt {
+    let quotient = num / den;
+    let remainder = num % den;
+    if !remainder.is_zero() && (remainder.sign() == Sign::Minus) != (den.sign() == Sign::Minus) {
+        quotient - BigInt::from(1)
+    } else {
+        quotient
+    }
+}
+
+/// Compute `floor(diff / increment)` exactly, on the unscaled `BigInt`s.
+fn div_floor_decimal(diff: &BigDecimal, increment: &BigDecimal) -> BigInt {
+    let (diff_digits, diff_scale) = diff.as_bigint_and_exponent();
+    let (increment_digits, increment_scale) = increment.as_bigint_and_exponent();
+    let scale_diff = increment_scale - diff_scale;
+    let (num, den) = if scale_diff >= 0 {
+        (
+            diff_digits * BigInt::from(10).pow(scale_diff as u32),
+            increment_digits,
+        )
+    } else {
+        (
+            diff_digits,
+            increment_digits * BigInt::from(10).pow((-scale_diff) as u32),
+        )
+    };
+    div_floor_bigint(&num, &den)
+}
+
+/// Compute the exact term count `floor((last - first) / increment) + 1`.
+/// Returns `None` if any of `first`/`increment`/`last` is `-0`, `Inf` or
+/// `NaN`, or if the count does not fit in a `u64`; the caller then falls
+/// back to incremental generation.
+fn exact_term_count(
+    first: &ExtendedBigDecimal,
+    increment: &ExtendedBigDecimal,
+    last: &ExtendedBigDecimal,
+) -> Option<(BigDecimal, BigDecimal, u64)> {
+    let (
+        ExtendedBigDecimal::BigDecimal(first),
+        ExtendedBigDecimal::BigDecimal(increment),
+        ExtendedBigDecimal::BigDecimal(last),
+    ) = (first, increment, last)
+    else {
+        return None;
+    };
+    let diff = last.clone() - first.clone();
+    let steps = div_floor_decimal(&diff, increment);
+    let n_terms = if steps.sign() == Sign::Minus {
+        0
+    } else {
+        (steps + BigInt::from(1)).to_u64()?
+    };
+    Some((first.clone(), increment.clone(), n_terms))
+}
+
+/// Below this many terms, chunking and spawning worker threads costs more
+/// than it saves; just render the sequence on the current thread.
+const MIN_CHUNKED_TERMS: u64 = 100_000;
+
+/// Generate `n_terms` elements as `first + i * increment`, splitting
+/// large ranges into chunks rendered concurrently and written in order.
+fn print_seq_chunked(
+    first: BigDecimal,
+    increment: BigDecimal,
+    n_terms: u64,
+    options: &RenderOptions,
 ) -> std::io::Result<()> {
     let stdout = stdout();
     let mut stdout = stdout.lock();
-    let (first, increment, last) = range;
-    let mut value = first;
-    let padding = if pad {
-        let precision_value = precision.unwrap_or(0);
-        padding
-            + if precision_value > 0 {
-                precision_value + 1
-            } else {
-                0
+    let separator = options.separator;
+
+    let term_value = |index: u64| -> ExtendedBigDecimal {
+        ExtendedBigDecimal::BigDecimal(first.clone() + BigDecimal::from(index) * increment.clone())
+    };
+
+    let worker_count = std::thread::available_parallelism()
+        .map(|n| n.get() as u64)
+        .unwrap_or(1);
+
+    if n_terms < MIN_CHUNKED_TERMS || worker_count <= 1 {
+        for index in 0..n_terms {
+            if index > 0 {
+                write!(stdout, "{separator}")?;
             }
+            render_term(&mut stdout, &term_value(index), options)?;
+        }
     } else {
-        0
-    };
+        let chunk_size = n_terms.div_ceil(worker_count);
+        let first = &first;
+        let increment = &increment;
+        let buffers = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..n_terms)
+                .step_by(chunk_size as usize)
+                .map(|start| {
+                    let count = chunk_size.min(n_terms - start);
+                    scope.spawn(move || -> std::io::Result<Vec<u8>> {
+                        let mut buf = Vec::new();
+                        for i in 0..count {
+                            let index = start + i;
+                            if index > 0 {
+                                write!(buf, "{separator}")?;
+                            }
+                            let value = ExtendedBigDecimal::BigDecimal(
+                                first.clone() + BigDecimal::from(index) * increment.clone(),
+                            );
+                            render_term(&mut buf, &value, options)?;
+                        }
+                        Ok(buf)
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<std::io::Result<Vec<_>>>()
+        })?;
+        for buf in buffers {
+            stdout.write_all(&buf)?;
+        }
+    }
+
+    if n_terms > 0 {
+        write!(stdout, "{}", options.terminator)?;
+    }
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Incremental fallback for ranges where the term count can't be
+/// computed exactly (e.g. `Inf`/`NaN` bounds): each term is derived from
+/// the previous one by repeated addition, as `seq` has always done.
+fn print_seq_incremental(range: RangeFloat, options: &RenderOptions) -> std::io::Result<()> {
+    let stdout = stdout();
+    let mut stdout = stdout.lock();
+    let (first, increment, last) = range;
+    let mut value = first;
     let mut is_first_iteration = true;
     while !done_printing(&value, &increment, &last) {
         if !is_first_iteration {
-            write!(stdout, "{separator}")?;
-        }
-        // If there was an argument `-f FORMAT`, then use that format
-        // template instead of the default formatting strategy.
-        //
-        // TODO The `printf()` method takes a string as its second
-        // parameter but we have an `ExtendedBigDecimal`. In order to
-        // satisfy the signature of the function, we convert the
-        // `ExtendedBigDecimal` into a string. The `printf()`
-        // logic will subsequently parse that string into something
-        // similar to an `ExtendedBigDecimal` again before rendering
-        // it as a string and ultimately writing to `stdout`. We
-        // shouldn't have to do so much converting back and forth via
-        // strings.
-        match &format {
-            Some(f) => {
-                let float = match &value {
-                    ExtendedBigDecimal::BigDecimal(bd) => bd.to_f64().unwrap(),
-                    ExtendedBigDecimal::Infinity => f64::INFINITY,
-                    ExtendedBigDecimal::MinusInfinity => f64::NEG_INFINITY,
-                    ExtendedBigDecimal::MinusZero => -0.0,
-                    ExtendedBigDecimal::Nan => f64::NAN,
-                };
-                f.fmt(&mut stdout, float)?;
-            }
-            None => write_value_float(&mut stdout, &value, padding, precision)?,
+            write!(stdout, "{}", options.separator)?;
         }
-        // TODO Implement augmenting addition.
+        render_term(&mut stdout, &value, options)?;
         value = value + increment.clone();
         is_first_iteration = false;
     }
     if !is_first_iteration {
-        write!(stdout, "{terminator}")?;
+        write!(stdout, "{}", options.terminator)?;
     }
     stdout.flush()?;
     Ok(())
 }
+
+/// Floating point based code path
+fn print_seq(range: RangeFloat, pad: bool, mut options: RenderOptions) -> std::io::Result<()> {
+    let (first, increment, last) = range;
+    options.padding = if pad {
+        let precision_value = options.precision.unwrap_or(0);
+        options.padding
+            + if precision_value > 0 {
+                precision_value + 1
+            } else {
+                0
+            }
+    } else {
+        0
+    };
+
+    match exact_term_count(&first, &increment, &last) {
+        Some((first, increment, n_terms)) => print_seq_chunked(first, increment, n_terms, &options),
+        None => print_seq_incremental((first, increment, last), &options),
+    }
+}
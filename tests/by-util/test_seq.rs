@@ -0,0 +1,131 @@
+// This file is part of the uutils coreutils package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+use crate::common::util::TestScenario;
+
+#[test]
+fn test_fixed_precision_round_trips_exactly() {
+    new_ucmd!()
+        .args(&["-f", "%.40f", "0", "0.1", "1"])
+        .succeeds()
+        .stdout_is(
+            "0.0000000000000000000000000000000000000000\n\
+             0.1000000000000000000000000000000000000000\n\
+             0.2000000000000000000000000000000000000000\n\
+             0.3000000000000000000000000000000000000000\n\
+             0.4000000000000000000000000000000000000000\n\
+             0.5000000000000000000000000000000000000000\n\
+             0.6000000000000000000000000000000000000000\n\
+             0.7000000000000000000000000000000000000000\n\
+             0.8000000000000000000000000000000000000000\n\
+             0.9000000000000000000000000000000000000000\n\
+             1.0000000000000000000000000000000000000000\n",
+        );
+}
+
+#[test]
+fn test_exponential_carry_propagates_past_precision() {
+    // 9.995 rounds up through the mantissa into the next power of ten.
+    new_ucmd!()
+        .args(&["-f", "%.2e", "9.995", "1", "9.995"])
+        .succeeds()
+        .stdout_is("1.00e+01\n");
+}
+
+#[test]
+fn test_general_format_strips_trailing_zeros() {
+    new_ucmd!()
+        .args(&["-f", "%g", "1", "1", "3"])
+        .succeeds()
+        .stdout_is("1\n2\n3\n");
+}
+
+#[test]
+fn test_minus_zero_formatting() {
+    new_ucmd!()
+        .args(&["-f", "%.2f", "--", "-0", "1", "-0"])
+        .succeeds()
+        .stdout_is("-0.00\n");
+}
+
+#[test]
+fn test_round_half_even_ties_to_even() {
+    new_ucmd!()
+        .args(&["-f", "%.0f", "--round", "half-even", "2.5", "1", "2.5"])
+        .succeeds()
+        .stdout_is("2\n");
+}
+
+#[test]
+fn test_round_floor_vs_ceiling() {
+    new_ucmd!()
+        .args(&["-f", "%.0f", "--round", "floor", "2.5", "1", "2.5"])
+        .succeeds()
+        .stdout_is("2\n");
+    new_ucmd!()
+        .args(&["-f", "%.0f", "--round", "ceiling", "2.5", "1", "2.5"])
+        .succeeds()
+        .stdout_is("3\n");
+}
+
+#[test]
+fn test_equal_width_zero_pads_negative_ranges() {
+    new_ucmd!()
+        .args(&["-w", "--", "-5", "1", "12"])
+        .succeeds()
+        .stdout_is("-5\n-4\n-3\n-2\n-1\n00\n01\n02\n03\n04\n05\n06\n07\n08\n09\n10\n11\n12\n");
+}
+
+#[test]
+fn test_invalid_round_mode_rejected() {
+    new_ucmd!()
+        .args(&["--round", "nope", "1", "3"])
+        .fails()
+        .code_is(1);
+}
+
+#[test]
+fn test_large_range_matches_incremental_output() {
+    // Exercises the parallel chunked code path (large term count) and checks
+    // it agrees with plain incremental generation at the boundaries.
+    let result = new_ucmd!().args(&["1", "100000"]).succeeds();
+    let stdout = result.stdout_str();
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next(), Some("1"));
+    assert_eq!(lines.last(), Some("100000"));
+    assert_eq!(stdout.lines().count(), 100000);
+}
+
+#[test]
+fn test_hexadecimal_format_with_width_and_alt_flag() {
+    new_ucmd!()
+        .args(&["-f", "%#010x", "255", "1", "257"])
+        .succeeds()
+        .stdout_is("0x000000ff\n0x00000100\n0x00000101\n");
+}
+
+#[test]
+fn test_uppercase_hex_and_octal_and_binary_formats() {
+    new_ucmd!()
+        .args(&["-f", "%X", "255", "1", "255"])
+        .succeeds()
+        .stdout_is("FF\n");
+    new_ucmd!()
+        .args(&["-f", "%#o", "8", "1", "8"])
+        .succeeds()
+        .stdout_is("010\n");
+    new_ucmd!()
+        .args(&["-f", "%#b", "5", "1", "5"])
+        .succeeds()
+        .stdout_is("0b101\n");
+}
+
+#[test]
+fn test_radix_format_rejects_non_integral_values() {
+    new_ucmd!()
+        .args(&["-f", "%x", "0.5", "1", "3"])
+        .fails()
+        .code_is(1)
+        .stderr_contains("requires an integer value");
+}